@@ -0,0 +1,414 @@
+//! A full 78-card French Tarot deck, plus shuffling and dealing.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::errors::{InvalidCardByteError, InvalidPlayerCountError};
+use crate::{Card, Color, ColorCard, Face, Theme, TrumpCard};
+
+/// A card of either kind.
+///
+/// `Card` is a trait implemented by two unrelated concrete types
+/// (`ColorCard` and `TrumpCard`), so there is no way to hold a mix of the
+/// two in a single collection. `AnyCard` wraps both so a `Deck` can store
+/// the whole 78-card set homogeneously.
+#[derive(Clone, Copy)]
+pub enum AnyCard {
+    Color(ColorCard),
+    Trump(TrumpCard),
+}
+
+impl AnyCard {
+    /// Returns whether this card is the Fool.
+    pub fn is_fool(&self) -> bool {
+        matches!(self, AnyCard::Trump(trump) if trump.is_fool())
+    }
+
+    /// Returns whether this card is an oudler (the Fool, the little one or the world).
+    pub fn is_oudler(&self) -> bool {
+        match self {
+            AnyCard::Color(_) => false,
+            AnyCard::Trump(trump) => trump.is_oudler(),
+        }
+    }
+}
+
+impl Card for AnyCard {
+    fn points(&self) -> f32 {
+        match self {
+            AnyCard::Color(card) => card.points(),
+            AnyCard::Trump(card) => card.points(),
+        }
+    }
+
+    fn rank(&self, theme: Theme) -> u8 {
+        match self {
+            AnyCard::Color(card) => card.rank(theme),
+            AnyCard::Trump(card) => card.rank(theme),
+        }
+    }
+}
+
+impl AnyCard {
+    /// Encodes this card as a single byte in `0..=77`.
+    ///
+    /// Color cards occupy `0..56`: `color_index * 14 + (number - 1)`, with
+    /// colors ordered clubs, spades, diamonds, hearts and `number` running
+    /// 1 (the lowest pip) to 14 (the King). Numbered trumps occupy `56..77`
+    /// (`56 + (n - 1)`), and the Fool is the reserved top value, `77`. This
+    /// encoding is stable and backs `AnyCard::all`, allocation-free hashing
+    /// and lookup tables.
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            AnyCard::Color(card) => color_index(card.color()) * 14 + (card.number() - 1),
+            AnyCard::Trump(TrumpCard::Number(n)) => 56 + (n - 1),
+            AnyCard::Trump(TrumpCard::Fool) => 77,
+        }
+    }
+
+    /// Returns an iterator over the whole 78-card deck, in `to_u8` order.
+    pub fn all() -> impl Iterator<Item = AnyCard> {
+        (0..=77u8).map(|byte| AnyCard::try_from(byte).expect("0..=77 are all valid card bytes"))
+    }
+}
+
+fn color_index(color: Color) -> u8 {
+    match color {
+        Color::Clubs => 0,
+        Color::Spades => 1,
+        Color::Diamonds => 2,
+        Color::Hearts => 3,
+    }
+}
+
+fn color_from_index(index: u8) -> Color {
+    match index {
+        0 => Color::Clubs,
+        1 => Color::Spades,
+        2 => Color::Diamonds,
+        _ => Color::Hearts,
+    }
+}
+
+impl TryFrom<u8> for AnyCard {
+    type Error = InvalidCardByteError;
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        match byte {
+            0..=55 => {
+                let color = color_from_index(byte / 14);
+                let number = (byte % 14) + 1;
+
+                Ok(if number <= 10 {
+                    AnyCard::Color(ColorCard::new_pip(number, color).unwrap())
+                } else {
+                    let face = match number {
+                        11 => Face::Jack,
+                        12 => Face::Knight,
+                        13 => Face::Queen,
+                        _ => Face::King,
+                    };
+                    AnyCard::Color(ColorCard::new_face(face, color))
+                })
+            }
+            56..=76 => Ok(AnyCard::Trump(TrumpCard::new_trump_card(byte - 55).unwrap())),
+            77 => Ok(AnyCard::Trump(TrumpCard::Fool)),
+            _ => Err(InvalidCardByteError::new(byte)),
+        }
+    }
+}
+
+impl AnyCard {
+    /// Sort key giving every card a distinct place in the conventional hand
+    /// order: trumps ascending (the Fool first, then 1 to 21), followed by
+    /// each suit's cards in ascending rank, suits ordered clubs, spades,
+    /// diamonds, hearts.
+    ///
+    /// This is what backs `AnyCard`'s `Ord` implementation, and resolves the
+    /// off-theme tie `Card::rank` has: unlike `rank`, two different cards
+    /// never share a key.
+    fn display_key(&self) -> (u8, u8) {
+        match self {
+            AnyCard::Trump(TrumpCard::Fool) => (0, 0),
+            AnyCard::Trump(TrumpCard::Number(n)) => (0, *n),
+            AnyCard::Color(card) => (1 + color_index(card.color()), card.number()),
+        }
+    }
+}
+
+impl PartialEq for AnyCard {
+    fn eq(&self, other: &Self) -> bool {
+        self.display_key() == other.display_key()
+    }
+}
+
+impl Eq for AnyCard {}
+
+impl PartialOrd for AnyCard {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AnyCard {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.display_key().cmp(&other.display_key())
+    }
+}
+
+impl From<ColorCard> for AnyCard {
+    fn from(card: ColorCard) -> Self {
+        AnyCard::Color(card)
+    }
+}
+
+impl From<TrumpCard> for AnyCard {
+    fn from(card: TrumpCard) -> Self {
+        AnyCard::Trump(card)
+    }
+}
+
+/// The 4 suit colors, in the order a full deck is built.
+const COLORS: [Color; 4] = [Color::Clubs, Color::Spades, Color::Diamonds, Color::Hearts];
+
+/// The 4 faces, in ascending rank order.
+const FACES: [Face; 4] = [Face::Jack, Face::Knight, Face::Queen, Face::King];
+
+/// The full 78-card French Tarot deck.
+///
+/// # Example
+/// ```
+/// # use tarot::*;
+/// let deck = Deck::full();
+/// assert_eq!(deck.cards().len(), 78);
+/// ```
+pub struct Deck {
+    cards: Vec<AnyCard>,
+}
+
+impl Deck {
+    /// Builds a full, unshuffled deck: the 56 color cards (4 colors, pips 1
+    /// to 10 plus the four faces), the 21 numbered trumps and the Fool.
+    pub fn full() -> Self {
+        let mut cards = Vec::with_capacity(78);
+
+        for &color in COLORS.iter() {
+            for number in 1..=10 {
+                cards.push(AnyCard::Color(ColorCard::new_pip(number, color).unwrap()));
+            }
+            for &face in FACES.iter() {
+                cards.push(AnyCard::Color(ColorCard::new_face(face, color)));
+            }
+        }
+
+        for number in 1..=21 {
+            cards.push(AnyCard::Trump(TrumpCard::new_trump_card(number).unwrap()));
+        }
+        cards.push(AnyCard::Trump(TrumpCard::Fool));
+
+        Deck { cards }
+    }
+
+    /// Returns the cards currently making up the deck.
+    pub fn cards(&self) -> &[AnyCard] {
+        &self.cards
+    }
+
+    /// Shuffles the deck in place using the given random number generator.
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Deals the deck into the standard hands for `players` players, plus
+    /// the chien (dog) set aside for the bidding phase.
+    ///
+    /// The chien is returned as the last hand of the result. It holds 6
+    /// cards for 3 or 4 players, 3 cards for 5 players.
+    ///
+    /// Returns an error if `players` isn't 3, 4 or 5, the only player
+    /// counts a French Tarot deck is dealt for.
+    pub fn deal(&self, players: usize) -> Result<Vec<Hand>, InvalidPlayerCountError> {
+        let (hand_size, chien_size) = match players {
+            3 => (24, 6),
+            4 => (18, 6),
+            5 => (15, 3),
+            _ => return Err(InvalidPlayerCountError::new(players)),
+        };
+
+        let mut cards = self.cards.iter().copied();
+        let mut hands: Vec<Hand> = (0..players)
+            .map(|_| Hand::new(cards.by_ref().take(hand_size).collect()))
+            .collect();
+        hands.push(Hand::new(cards.by_ref().take(chien_size).collect()));
+
+        Ok(hands)
+    }
+}
+
+/// A player's hand, or the chien (dog) set aside during dealing.
+pub struct Hand {
+    cards: Vec<AnyCard>,
+}
+
+impl Hand {
+    /// Creates a hand out of the given cards.
+    pub fn new(cards: Vec<AnyCard>) -> Self {
+        Hand { cards }
+    }
+
+    /// Returns the cards in this hand.
+    pub fn cards(&self) -> &[AnyCard] {
+        &self.cards
+    }
+
+    /// Returns the number of cards in this hand.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns whether this hand holds no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Removes the first occurrence of `card` from this hand, returning
+    /// whether it was found.
+    ///
+    /// Callers should remove a card from a player's hand as soon as it's
+    /// played, so that `Trick::is_legal`/`Trick::play` keep validating
+    /// against what the player actually still holds from one trick to the
+    /// next.
+    pub fn remove(&mut self, card: &AnyCard) -> bool {
+        match self.cards.iter().position(|c| c == card) {
+            Some(index) => {
+                self.cards.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sorts the hand's cards into the conventional display order: trumps
+    /// ascending (the Fool first), then each suit ascending by rank.
+    pub fn sort(&mut self) {
+        self.cards.sort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_deck_has_78_cards() {
+        let deck = Deck::full();
+        assert_eq!(deck.cards().len(), 78);
+    }
+
+    #[test]
+    fn test_full_deck_points_sum_to_91() {
+        let deck = Deck::full();
+        let total: f32 = deck.cards().iter().map(Card::points).sum();
+        assert_eq!(total, 91.0);
+    }
+
+    #[test]
+    fn test_deal_four_players() {
+        let deck = Deck::full();
+        let hands = deck.deal(4).unwrap();
+
+        assert_eq!(hands.len(), 5);
+        for hand in &hands[..4] {
+            assert_eq!(hand.len(), 18);
+        }
+        assert_eq!(hands[4].len(), 6);
+    }
+
+    #[test]
+    fn test_deal_five_players() {
+        let deck = Deck::full();
+        let hands = deck.deal(5).unwrap();
+
+        assert_eq!(hands.len(), 6);
+        for hand in &hands[..5] {
+            assert_eq!(hand.len(), 15);
+        }
+        assert_eq!(hands[5].len(), 3);
+    }
+
+    #[test]
+    fn test_deal_rejects_invalid_player_count() {
+        let deck = Deck::full();
+        assert!(deck.deal(2).is_err());
+        assert!(deck.deal(6).is_err());
+    }
+
+    #[test]
+    fn test_u8_roundtrip_for_every_card() {
+        for card in Deck::full().cards() {
+            let byte = card.to_u8();
+            let back = AnyCard::try_from(byte).unwrap();
+            assert_eq!(back.to_u8(), byte);
+        }
+    }
+
+    #[test]
+    fn test_all_yields_78_distinct_bytes() {
+        let bytes: Vec<u8> = AnyCard::all().map(|card| card.to_u8()).collect();
+        assert_eq!(bytes.len(), 78);
+        assert_eq!(bytes, (0..=77).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_fool_is_reserved_top_byte() {
+        assert_eq!(AnyCard::Trump(TrumpCard::Fool).to_u8(), 77);
+        assert!(matches!(AnyCard::try_from(77), Ok(AnyCard::Trump(TrumpCard::Fool))));
+    }
+
+    #[test]
+    fn test_try_from_rejects_out_of_range_byte() {
+        assert!(AnyCard::try_from(78).is_err());
+        assert!(AnyCard::try_from(255).is_err());
+    }
+
+    #[test]
+    fn test_fool_sorts_before_numbered_trumps() {
+        let fool = AnyCard::Trump(TrumpCard::Fool);
+        let little_one = AnyCard::Trump(TrumpCard::little_one());
+        assert!(fool < little_one);
+    }
+
+    #[test]
+    fn test_trumps_sort_before_color_cards() {
+        let the_world = AnyCard::Trump(TrumpCard::the_world());
+        let ace_of_hearts = AnyCard::Color(ColorCard::new_pip(1, Color::Hearts).unwrap());
+        assert!(the_world < ace_of_hearts);
+    }
+
+    #[test]
+    fn test_hand_sort_orders_distinct_off_theme_cards() {
+        let mut hand = Hand::new(vec![
+            AnyCard::Color(ColorCard::new_face(Face::King, Color::Spades)),
+            AnyCard::Trump(TrumpCard::Fool),
+            AnyCard::Color(ColorCard::new_pip(3, Color::Clubs).unwrap()),
+        ]);
+
+        hand.sort();
+
+        assert!(matches!(hand.cards()[0], AnyCard::Trump(TrumpCard::Fool)));
+        assert!(matches!(hand.cards()[1], AnyCard::Color(ref c) if c.color() == Color::Clubs));
+        assert!(matches!(hand.cards()[2], AnyCard::Color(ref c) if c.color() == Color::Spades));
+    }
+
+    #[test]
+    fn test_hand_remove() {
+        let mut hand = Hand::new(vec![
+            AnyCard::Trump(TrumpCard::Fool),
+            AnyCard::Color(ColorCard::new_pip(7, Color::Diamonds).unwrap()),
+        ]);
+
+        assert!(hand.remove(&AnyCard::Trump(TrumpCard::Fool)));
+        assert_eq!(hand.len(), 1);
+        assert!(!hand.remove(&AnyCard::Trump(TrumpCard::Fool)));
+    }
+}
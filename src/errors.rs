@@ -56,3 +56,125 @@ impl Display for TrumpValueError {
 impl Error for TrumpValueError {
 
 }
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidPlayerCountError {
+  count: usize
+}
+
+impl InvalidPlayerCountError {
+  pub fn new(count: usize) -> Self {
+    Self {
+      count
+    }
+  }
+}
+
+impl Display for InvalidPlayerCountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is not a valid number of players to deal a tarot deck to (expected 3, 4 or 5)", self.count)
+    }
+}
+
+impl Error for InvalidPlayerCountError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct IllegalPlayError;
+
+impl IllegalPlayError {
+  pub fn new() -> Self {
+    IllegalPlayError
+  }
+}
+
+impl Display for IllegalPlayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "this card can't legally be played in the current trick")
+    }
+}
+
+impl Error for IllegalPlayError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidCardNotationError {
+  notation: String
+}
+
+impl InvalidCardNotationError {
+  pub fn new(notation: &str) -> Self {
+    Self {
+      notation: notation.to_string()
+    }
+  }
+}
+
+impl Display for InvalidCardNotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\" is not a valid card notation", self.notation)
+    }
+}
+
+impl Error for InvalidCardNotationError {}
+
+/// Error returned when parsing a card from its textual notation fails,
+/// either because the notation itself is malformed or because it encodes
+/// an out-of-range pip or trump value.
+#[derive(Debug)]
+pub enum CardParseError {
+  Notation(InvalidCardNotationError),
+  Pip(PipValueError),
+  Trump(TrumpValueError)
+}
+
+impl Display for CardParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CardParseError::Notation(e) => e.fmt(f),
+            CardParseError::Pip(e) => e.fmt(f),
+            CardParseError::Trump(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for CardParseError {}
+
+impl From<InvalidCardNotationError> for CardParseError {
+  fn from(e: InvalidCardNotationError) -> Self {
+    CardParseError::Notation(e)
+  }
+}
+
+impl From<PipValueError> for CardParseError {
+  fn from(e: PipValueError) -> Self {
+    CardParseError::Pip(e)
+  }
+}
+
+impl From<TrumpValueError> for CardParseError {
+  fn from(e: TrumpValueError) -> Self {
+    CardParseError::Trump(e)
+  }
+}
+
+/// Error returned when a byte is out of the `0..=77` range backing the
+/// compact [`AnyCard`](crate::AnyCard) encoding.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InvalidCardByteError {
+  byte: u8
+}
+
+impl InvalidCardByteError {
+  pub fn new(byte: u8) -> Self {
+    Self {
+      byte
+    }
+  }
+}
+
+impl Display for InvalidCardByteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} doesn't encode any card (valid range is 0..=77)", self.byte)
+    }
+}
+
+impl Error for InvalidCardByteError {}
@@ -8,12 +8,39 @@
 //! which is used to know how cards compare and which one wins the trick.
 //! `Face` and `Color` are used to represent face cards and suit colors
 //! respectively.
+//!
+//! `AnyCard` wraps both card types so a full `Deck` can be built, shuffled
+//! and dealt into `Hand`s.
+//!
+//! A `Trick` accumulates the cards played in a round and resolves the
+//! winner, taking the Fool's special "excuse" rule into account.
+//!
+//! `ContractResult` turns the taker's won pile into a score, given the
+//! `Bid` they announced.
+//!
+//! Enabling the `serde` feature adds `Serialize`/`Deserialize` support for
+//! `Color`, `Face`, `ColorCard`, `TrumpCard` and `Theme`.
+//!
+//! `ColorCard`, `TrumpCard` and `AnyCard` also implement `Display` and
+//! `FromStr`, so cards round-trip through a compact notation such as `KH`,
+//! `7D`, `T21` or `EXC` for the Fool.
 
+mod deck;
 mod errors;
+mod notation;
+mod scoring;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod trick;
 
 use crate::errors::{PipValueError, TrumpValueError};
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+pub use crate::deck::{AnyCard, Deck, Hand};
+pub use crate::scoring::{Bid, ContractResult};
+pub use crate::trick::{FoolExchange, Trick, TrickResult};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Enum representing the four different suits colors.
 pub enum Color {
     Clubs,
@@ -22,7 +49,8 @@ pub enum Color {
     Hearts,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Enum representing the fous different faces ranks.
 pub enum Face {
     King,
@@ -69,6 +97,33 @@ pub trait Card {
     /// assert!(little_one.rank(hearts_theme) > hearts_king.rank(hearts_theme));
     /// ```
     fn rank(&self, theme: Theme) -> u8;
+
+    /// Returns whether this card beats `other` when led under `theme`.
+    ///
+    /// This simply compares `rank(theme)` for both cards, so it inherits
+    /// `rank`'s quirk: a card that isn't part of `theme` (an off-theme color
+    /// card, or any color card when `theme` is trumps) always ranks 0, so
+    /// two different off-theme cards never beat each other here. To sort or
+    /// compare cards outside of a specific trick's theme, use `AnyCard`'s
+    /// `Ord` implementation instead, which gives every card a distinct
+    /// place in the conventional hand order.
+    ///
+    /// # Example
+    /// ```
+    /// # use tarot::*;
+    /// let hearts_theme = Theme::Color(Color::Hearts);
+    /// let hearts_king = ColorCard::new_face(Face::King, Color::Hearts);
+    /// let little_one = TrumpCard::little_one();
+    ///
+    /// assert!(little_one.beats(&hearts_king, hearts_theme));
+    /// assert!(!hearts_king.beats(&little_one, hearts_theme));
+    /// ```
+    fn beats(&self, other: &impl Card, theme: Theme) -> bool
+    where
+        Self: Sized,
+    {
+        self.rank(theme) > other.rank(theme)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -114,6 +169,7 @@ impl Theme {
 ///
 /// let seven_of_diamonds = ColorCard::new_pip(7, Color::Diamonds);
 /// ```
+#[derive(Clone, Copy)]
 pub struct ColorCard {
     color: Color,
     face: Option<Face>,
@@ -188,6 +244,7 @@ impl ColorCard {
 ///
 /// let fifteen_of_trumps = TrumpCard::new_trump_card(15).unwrap();
 /// ```
+#[derive(Clone, Copy)]
 pub enum TrumpCard {
     Number(u8),
     Fool,
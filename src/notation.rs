@@ -0,0 +1,226 @@
+//! `Display` and `FromStr` for the crate's compact card notation: `KH` for
+//! the King of Hearts, `7D` for the 7 of Diamonds, `T21` for trump 21, and
+//! `EXC` or `*` for the Fool.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::{CardParseError, InvalidCardNotationError};
+use crate::{AnyCard, Color, ColorCard, Face, TrumpCard};
+
+impl Color {
+    fn suit_char(self) -> char {
+        match self {
+            Color::Hearts => 'H',
+            Color::Diamonds => 'D',
+            Color::Clubs => 'T',
+            Color::Spades => 'S',
+        }
+    }
+
+    fn suit_glyph(self) -> char {
+        match self {
+            Color::Hearts => '♥',
+            Color::Diamonds => '♦',
+            Color::Clubs => '♣',
+            Color::Spades => '♠',
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    /// Writes the suit's single-char notation (`H`, `D`, `T`, `S`), or its
+    /// Unicode glyph (`♥ ♦ ♣ ♠`) when formatted with the `#` alternate flag.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.suit_glyph())
+        } else {
+            write!(f, "{}", self.suit_char())
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = InvalidCardNotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "H" => Ok(Color::Hearts),
+            "D" => Ok(Color::Diamonds),
+            "T" => Ok(Color::Clubs),
+            "S" => Ok(Color::Spades),
+            _ => Err(InvalidCardNotationError::new(s)),
+        }
+    }
+}
+
+impl Face {
+    fn rank_char(self) -> char {
+        match self {
+            Face::King => 'K',
+            Face::Queen => 'Q',
+            Face::Knight => 'C',
+            Face::Jack => 'J',
+        }
+    }
+}
+
+impl fmt::Display for Face {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rank_char())
+    }
+}
+
+impl FromStr for Face {
+    type Err = InvalidCardNotationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "K" => Ok(Face::King),
+            "Q" => Ok(Face::Queen),
+            "C" => Ok(Face::Knight),
+            "J" => Ok(Face::Jack),
+            _ => Err(InvalidCardNotationError::new(s)),
+        }
+    }
+}
+
+impl fmt::Display for ColorCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.face() {
+            Some(face) => write!(f, "{}{}", face, self.color()),
+            None => write!(f, "{}{}", self.number(), self.color()),
+        }
+    }
+}
+
+impl FromStr for ColorCard {
+    type Err = CardParseError;
+
+    /// Parses notations such as `KH` (King of Hearts) or `7D` (7 of
+    /// Diamonds): every character but the last is the rank, the last one is
+    /// the suit.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = match s.char_indices().last() {
+            Some((index, _)) if index > 0 => index,
+            _ => return Err(InvalidCardNotationError::new(s).into()),
+        };
+
+        let (rank, suit) = s.split_at(split);
+        let color: Color = suit.parse().map_err(|_| InvalidCardNotationError::new(s))?;
+
+        if let Ok(face) = rank.parse::<Face>() {
+            return Ok(ColorCard::new_face(face, color));
+        }
+
+        let number: u8 = rank.parse().map_err(|_| InvalidCardNotationError::new(s))?;
+        Ok(ColorCard::new_pip(number, color)?)
+    }
+}
+
+impl fmt::Display for TrumpCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrumpCard::Fool => write!(f, "EXC"),
+            TrumpCard::Number(n) => write!(f, "T{}", n),
+        }
+    }
+}
+
+impl FromStr for TrumpCard {
+    type Err = CardParseError;
+
+    /// Parses `T<number>` (e.g. `T21`), `EXC` or `*` for the Fool.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.to_ascii_uppercase();
+
+        if upper == "EXC" || upper == "*" {
+            return Ok(TrumpCard::Fool);
+        }
+
+        let digits = upper
+            .strip_prefix('T')
+            .ok_or_else(|| InvalidCardNotationError::new(s))?;
+        let value: u8 = digits.parse().map_err(|_| InvalidCardNotationError::new(s))?;
+
+        Ok(TrumpCard::new_trump_card(value)?)
+    }
+}
+
+impl fmt::Display for AnyCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnyCard::Color(card) => card.fmt(f),
+            AnyCard::Trump(card) => card.fmt(f),
+        }
+    }
+}
+
+impl FromStr for AnyCard {
+    type Err = CardParseError;
+
+    /// Tries trump notation (`T<number>`, `EXC`, `*`) first, falling back
+    /// to color card notation otherwise.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = s.to_ascii_uppercase();
+
+        if upper == "EXC" || upper == "*" || upper.starts_with('T') {
+            return s.parse::<TrumpCard>().map(AnyCard::Trump);
+        }
+
+        s.parse::<ColorCard>().map(AnyCard::Color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_card_roundtrip() {
+        let king = ColorCard::new_face(Face::King, Color::Hearts);
+        assert_eq!(king.to_string(), "KH");
+        assert_eq!("KH".parse::<ColorCard>().unwrap().to_string(), "KH");
+
+        let pip = ColorCard::new_pip(7, Color::Diamonds).unwrap();
+        assert_eq!(pip.to_string(), "7D");
+        assert_eq!("7D".parse::<ColorCard>().unwrap().to_string(), "7D");
+    }
+
+    #[test]
+    fn test_trump_card_roundtrip() {
+        let the_world = TrumpCard::the_world();
+        assert_eq!(the_world.to_string(), "T21");
+        assert_eq!("T21".parse::<TrumpCard>().unwrap().to_string(), "T21");
+
+        assert!(matches!("EXC".parse::<TrumpCard>(), Ok(TrumpCard::Fool)));
+        assert!(matches!("*".parse::<TrumpCard>(), Ok(TrumpCard::Fool)));
+        assert_eq!(TrumpCard::Fool.to_string(), "EXC");
+    }
+
+    #[test]
+    fn test_any_card_roundtrip() {
+        assert!(matches!("T1".parse::<AnyCard>(), Ok(AnyCard::Trump(_))));
+        assert!(matches!("QS".parse::<AnyCard>(), Ok(AnyCard::Color(_))));
+        assert_eq!("T1".parse::<AnyCard>().unwrap().to_string(), "T1");
+        assert_eq!("QS".parse::<AnyCard>().unwrap().to_string(), "QS");
+    }
+
+    #[test]
+    fn test_knight_and_clubs_use_distinct_letters() {
+        let knight_of_clubs = ColorCard::new_face(Face::Knight, Color::Clubs);
+        assert_eq!(knight_of_clubs.to_string(), "CT");
+
+        let parsed = "CT".parse::<ColorCard>().unwrap();
+        assert_eq!(parsed.face(), Some(Face::Knight));
+        assert_eq!(parsed.color(), Color::Clubs);
+    }
+
+    #[test]
+    fn test_invalid_notation_is_rejected() {
+        assert!("".parse::<ColorCard>().is_err());
+        assert!("ZZ".parse::<ColorCard>().is_err());
+        assert!("T99".parse::<TrumpCard>().is_err());
+        assert!("99H".parse::<ColorCard>().is_err());
+    }
+}
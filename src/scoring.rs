@@ -0,0 +1,141 @@
+//! Contract scoring: turning a taker's won pile into a result and a score.
+
+use crate::{AnyCard, Card};
+
+/// The bid (contract) announced by the taker, each with its own score
+/// multiplier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bid {
+    Petite,
+    Garde,
+    GardeSans,
+    GardeContre,
+}
+
+impl Bid {
+    /// Returns the multiplier applied to the base score for this bid.
+    pub fn coefficient(&self) -> f32 {
+        match self {
+            Bid::Petite => 1.0,
+            Bid::Garde => 2.0,
+            Bid::GardeSans => 4.0,
+            Bid::GardeContre => 6.0,
+        }
+    }
+}
+
+/// The result of scoring the taker's won pile against the target set by
+/// their oudlers.
+///
+/// # Example
+/// ```
+/// # use tarot::*;
+/// let pile = vec![
+///     AnyCard::Trump(TrumpCard::the_world()),
+///     AnyCard::Color(ColorCard::new_face(Face::King, Color::Hearts)),
+/// ];
+/// let result = ContractResult::new(&pile);
+/// assert_eq!(result.oudlers, 1);
+/// assert_eq!(result.target, 51.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContractResult {
+    /// Number of oudlers (the Fool, the little one, the world) in the pile.
+    pub oudlers: u8,
+    /// Total points (card values) in the pile.
+    pub points: f32,
+    /// Points the taker needed to reach, set by their oudler count.
+    pub target: f32,
+    /// `points - target`: zero or positive when the contract is made.
+    pub diff: f32,
+    /// Whether the taker reached their target.
+    pub made: bool,
+}
+
+impl ContractResult {
+    /// Scores `pile`, the cards taken by the taker over the whole deal.
+    ///
+    /// The target depends on how many oudlers the pile holds: 36 points for
+    /// 3 oudlers, 41 for 2, 51 for 1, 56 for none.
+    pub fn new<'a>(pile: impl IntoIterator<Item = &'a AnyCard>) -> Self {
+        let mut oudlers = 0u8;
+        let mut points = 0.0;
+
+        for card in pile {
+            points += card.points();
+            if card.is_oudler() {
+                oudlers += 1;
+            }
+        }
+
+        let target = match oudlers {
+            3 => 36.0,
+            2 => 41.0,
+            1 => 51.0,
+            _ => 56.0,
+        };
+        let diff = points - target;
+
+        ContractResult {
+            oudlers,
+            points,
+            target,
+            diff,
+            made: diff >= 0.0,
+        }
+    }
+
+    /// Returns the signed score for this result under the given `bid`.
+    ///
+    /// The base score `25 + diff.abs().ceil()` is multiplied by the bid's
+    /// coefficient, then negated if the contract wasn't made.
+    pub fn score(&self, bid: Bid) -> f32 {
+        let base = (25.0 + self.diff.abs().ceil()) * bid.coefficient();
+        if self.made {
+            base
+        } else {
+            -base
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, ColorCard, Face, TrumpCard};
+
+    #[test]
+    fn test_target_depends_on_oudler_count() {
+        let no_oudlers = ContractResult::new(&Vec::<AnyCard>::new());
+        assert_eq!(no_oudlers.target, 56.0);
+
+        let one_oudler = ContractResult::new(&[AnyCard::Trump(TrumpCard::little_one())]);
+        assert_eq!(one_oudler.target, 51.0);
+
+        let two_oudlers = ContractResult::new(&[
+            AnyCard::Trump(TrumpCard::little_one()),
+            AnyCard::Trump(TrumpCard::the_world()),
+        ]);
+        assert_eq!(two_oudlers.target, 41.0);
+
+        let three_oudlers = ContractResult::new(&[
+            AnyCard::Trump(TrumpCard::little_one()),
+            AnyCard::Trump(TrumpCard::the_world()),
+            AnyCard::Trump(TrumpCard::Fool),
+        ]);
+        assert_eq!(three_oudlers.target, 36.0);
+    }
+
+    #[test]
+    fn test_made_and_score() {
+        let pile = vec![
+            AnyCard::Trump(TrumpCard::little_one()),
+            AnyCard::Color(ColorCard::new_face(Face::King, Color::Hearts)),
+        ];
+        // 4.5 + 4.5 = 9 points, nowhere near the 51 target.
+        let result = ContractResult::new(&pile);
+        assert!(!result.made);
+        assert!(result.score(Bid::Petite) < 0.0);
+        assert_eq!(result.score(Bid::Garde), result.score(Bid::Petite) * 2.0);
+    }
+}
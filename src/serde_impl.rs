@@ -0,0 +1,145 @@
+//! Manual `serde` support for the card types that carry data and need
+//! validation on the way back in.
+//!
+//! Each of these is represented as an internally-tagged object, e.g.
+//! `{"type":"color","color":"Hearts"}` or `{"type":"fool"}`. Deserializing a
+//! `ColorCard` or a `TrumpCard` always goes through its validating
+//! constructor (`new_pip`/`new_trump_card`), so an out-of-range number
+//! surfaces as a serde error rather than constructing an invalid card.
+//!
+//! `Color` and `Face` are plain unit enums and simply derive `Serialize`/
+//! `Deserialize` where they're defined.
+
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Color, ColorCard, Face, Theme, TrumpCard};
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum ThemeRepr {
+    #[serde(rename = "trump")]
+    Trump,
+    #[serde(rename = "color")]
+    Color { color: Color },
+}
+
+impl Serialize for Theme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Theme::Trump => ThemeRepr::Trump.serialize(serializer),
+            Theme::Color(color) => ThemeRepr::Color { color: *color }.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match ThemeRepr::deserialize(deserializer)? {
+            ThemeRepr::Trump => Ok(Theme::Trump),
+            ThemeRepr::Color { color } => Ok(Theme::Color(color)),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum ColorCardRepr {
+    #[serde(rename = "face")]
+    Face { color: Color, face: Face },
+    #[serde(rename = "pip")]
+    Pip { color: Color, number: u8 },
+}
+
+impl Serialize for ColorCard {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr = match self.face() {
+            Some(face) => ColorCardRepr::Face {
+                color: self.color(),
+                face,
+            },
+            None => ColorCardRepr::Pip {
+                color: self.color(),
+                number: self.number(),
+            },
+        };
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorCard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match ColorCardRepr::deserialize(deserializer)? {
+            ColorCardRepr::Face { color, face } => Ok(ColorCard::new_face(face, color)),
+            ColorCardRepr::Pip { color, number } => {
+                ColorCard::new_pip(number, color).map_err(DeError::custom)
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum TrumpCardRepr {
+    #[serde(rename = "fool")]
+    Fool,
+    #[serde(rename = "number")]
+    Number { value: u8 },
+}
+
+impl Serialize for TrumpCard {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            TrumpCard::Fool => TrumpCardRepr::Fool.serialize(serializer),
+            TrumpCard::Number(value) => TrumpCardRepr::Number { value: *value }.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TrumpCard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match TrumpCardRepr::deserialize(deserializer)? {
+            TrumpCardRepr::Fool => Ok(TrumpCard::Fool),
+            TrumpCardRepr::Number { value } => {
+                TrumpCard::new_trump_card(value).map_err(DeError::custom)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_card_roundtrips_through_validating_constructor() {
+        let card = ColorCard::new_pip(7, Color::Diamonds).unwrap();
+        let json = serde_json::to_string(&card).unwrap();
+        let back: ColorCard = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.color(), Color::Diamonds);
+        assert_eq!(back.number(), 7);
+    }
+
+    #[test]
+    fn test_color_card_deserialize_rejects_invalid_number() {
+        let json = r#"{"type":"pip","color":"Hearts","number":0}"#;
+        let result: Result<ColorCard, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_trump_card_deserialize_rejects_invalid_value() {
+        let json = r#"{"type":"number","value":99}"#;
+        let result: Result<TrumpCard, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_theme_roundtrip() {
+        let json = serde_json::to_string(&Theme::Color(Color::Spades)).unwrap();
+        assert_eq!(json, r#"{"type":"color","color":"Spades"}"#);
+
+        let back: Theme = serde_json::from_str(&json).unwrap();
+        assert!(matches!(back, Theme::Color(Color::Spades)));
+    }
+}
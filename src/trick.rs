@@ -0,0 +1,315 @@
+//! Trick resolution: following suit, over-trumping and the Fool's excuse.
+
+use crate::errors::IllegalPlayError;
+use crate::{AnyCard, Card, Hand, Theme};
+
+/// What happens to the Fool (the excuse) at the end of a trick.
+///
+/// In French Tarot the Fool never wins a trick; it stays with whoever
+/// played it, the "excuse" rule.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FoolExchange {
+    /// The Fool wasn't played in this trick.
+    NotPlayed,
+    /// The Fool was played by the trick's winner, who simply keeps it.
+    KeptByWinner,
+    /// The Fool was played by a player other than the winner; it is
+    /// returned to them rather than awarded to the winner.
+    ReturnedToPlayer(usize),
+}
+
+/// The outcome of a resolved trick.
+#[derive(Debug, PartialEq)]
+pub struct TrickResult {
+    /// Index of the player who won the trick.
+    pub winner: usize,
+    /// Total points (card values) contained in the trick.
+    pub points: f32,
+    /// What happens to the Fool, if it was played.
+    pub fool_exchange: FoolExchange,
+}
+
+/// A trick in progress: the cards played so far, in play order.
+///
+/// The trick's theme (the suit to follow, or trumps) is inferred from the
+/// first non-Fool card led; leading with the Fool doesn't fix a theme, so
+/// the next card played decides it instead.
+pub struct Trick {
+    theme: Option<Theme>,
+    plays: Vec<(usize, AnyCard)>,
+}
+
+impl Trick {
+    /// Creates a new, empty trick.
+    pub fn new() -> Self {
+        Trick {
+            theme: None,
+            plays: Vec::new(),
+        }
+    }
+
+    /// Returns the trick's theme, if one has been established yet.
+    pub fn theme(&self) -> Option<Theme> {
+        self.theme
+    }
+
+    /// Returns the cards played so far, along with the index of the player
+    /// who played each one, in play order.
+    pub fn plays(&self) -> &[(usize, AnyCard)] {
+        &self.plays
+    }
+
+    /// Returns whether playing `card` out of `hand` is legal given the
+    /// cards already played in this trick.
+    ///
+    /// A card that isn't in `hand` is never legal. The Fool can otherwise
+    /// always be played. Failing that, a player holding a card of the led
+    /// color must play one; failing that, a player holding a trump that
+    /// beats every trump already in the trick must play one of those (the
+    /// "must over-trump" rule); if none of their trumps are high enough,
+    /// any trump they hold is legal instead.
+    pub fn is_legal(&self, card: &AnyCard, hand: &Hand) -> bool {
+        if !hand.cards().contains(card) {
+            return false;
+        }
+
+        let theme = match self.theme {
+            None => return true,
+            Some(theme) => theme,
+        };
+
+        if card.is_fool() {
+            return true;
+        }
+
+        if theme.is_color() {
+            let led_color = theme.color_checked();
+            let has_led_color = hand
+                .cards()
+                .iter()
+                .any(|c| matches!(c, AnyCard::Color(cc) if cc.color() == led_color));
+
+            if has_led_color {
+                return matches!(card, AnyCard::Color(cc) if cc.color() == led_color);
+            }
+        }
+
+        let has_trump = hand
+            .cards()
+            .iter()
+            .any(|c| matches!(c, AnyCard::Trump(t) if !t.is_fool()));
+
+        if has_trump {
+            if !matches!(card, AnyCard::Trump(t) if !t.is_fool()) {
+                return false;
+            }
+
+            let highest_trump = self.highest_trump_rank();
+            let can_over_trump = hand.cards().iter().any(|c| {
+                matches!(c, AnyCard::Trump(t) if !t.is_fool()) && c.rank(Theme::Trump) > highest_trump
+            });
+
+            if can_over_trump {
+                return card.rank(Theme::Trump) > highest_trump;
+            }
+        }
+
+        true
+    }
+
+    /// Plays `card` on behalf of `player`, validating it against `hand`
+    /// first, and establishing the trick's theme if this is the first
+    /// non-Fool card played.
+    pub fn play(&mut self, player: usize, card: AnyCard, hand: &Hand) -> Result<(), IllegalPlayError> {
+        if !self.is_legal(&card, hand) {
+            return Err(IllegalPlayError::new());
+        }
+
+        if self.theme.is_none() {
+            self.theme = match card {
+                AnyCard::Color(cc) => Some(Theme::Color(cc.color())),
+                AnyCard::Trump(ref t) if !t.is_fool() => Some(Theme::Trump),
+                AnyCard::Trump(_) => None,
+            };
+        }
+
+        self.plays.push((player, card));
+        Ok(())
+    }
+
+    /// Resolves the trick, returning the winner, the points it's worth and
+    /// the Fool's bookkeeping.
+    ///
+    /// Returns `None` if no theme has been established yet (the trick is
+    /// either empty, or only the Fool has been played so far).
+    pub fn winner(&self) -> Option<TrickResult> {
+        let theme = self.theme?;
+
+        let points = self.plays.iter().map(|(_, card)| card.points()).sum();
+
+        let winner = self
+            .plays
+            .iter()
+            .filter(|(_, card)| !card.is_fool())
+            .max_by_key(|(_, card)| card.rank(theme))
+            .map(|&(player, _)| player)?;
+
+        let fool_exchange = self
+            .plays
+            .iter()
+            .find(|(_, card)| card.is_fool())
+            .map(|&(player, _)| {
+                if player == winner {
+                    FoolExchange::KeptByWinner
+                } else {
+                    FoolExchange::ReturnedToPlayer(player)
+                }
+            })
+            .unwrap_or(FoolExchange::NotPlayed);
+
+        Some(TrickResult {
+            winner,
+            points,
+            fool_exchange,
+        })
+    }
+
+    fn highest_trump_rank(&self) -> u8 {
+        self.plays
+            .iter()
+            .map(|(_, card)| card)
+            .filter(|card| matches!(card, AnyCard::Trump(t) if !t.is_fool()))
+            .map(|card| card.rank(Theme::Trump))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Default for Trick {
+    fn default() -> Self {
+        Trick::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, ColorCard, TrumpCard};
+
+    fn hand_of(cards: Vec<AnyCard>) -> Hand {
+        Hand::new(cards)
+    }
+
+    #[test]
+    fn test_first_card_establishes_theme() {
+        let mut trick = Trick::new();
+        let hand = hand_of(vec![AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap())]);
+
+        trick
+            .play(0, AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap()), &hand)
+            .unwrap();
+
+        assert!(matches!(trick.theme(), Some(Theme::Color(Color::Hearts))));
+    }
+
+    #[test]
+    fn test_card_not_in_hand_is_never_legal() {
+        let mut trick = Trick::new();
+        let hand = hand_of(vec![AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap())]);
+
+        assert!(!trick.is_legal(&AnyCard::Trump(TrumpCard::Fool), &hand));
+        assert!(!trick.is_legal(&AnyCard::Color(ColorCard::new_pip(2, Color::Clubs).unwrap()), &hand));
+        assert!(trick.play(1, AnyCard::Trump(TrumpCard::Fool), &hand).is_err());
+    }
+
+    #[test]
+    fn test_must_follow_led_color() {
+        let mut trick = Trick::new();
+        let leader_hand = hand_of(vec![AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap())]);
+        trick
+            .play(0, AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap()), &leader_hand)
+            .unwrap();
+
+        let follower_hand = hand_of(vec![
+            AnyCard::Color(ColorCard::new_pip(2, Color::Hearts).unwrap()),
+            AnyCard::Color(ColorCard::new_pip(9, Color::Clubs).unwrap()),
+        ]);
+
+        assert!(!trick.is_legal(
+            &AnyCard::Color(ColorCard::new_pip(9, Color::Clubs).unwrap()),
+            &follower_hand
+        ));
+        assert!(trick.is_legal(
+            &AnyCard::Color(ColorCard::new_pip(2, Color::Hearts).unwrap()),
+            &follower_hand
+        ));
+    }
+
+    #[test]
+    fn test_must_over_trump_when_void() {
+        let mut trick = Trick::new();
+        let leader_hand = hand_of(vec![AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap())]);
+        trick
+            .play(0, AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap()), &leader_hand)
+            .unwrap();
+
+        let void_hand = hand_of(vec![
+            AnyCard::Trump(TrumpCard::new_trump_card(3).unwrap()),
+            AnyCard::Trump(TrumpCard::new_trump_card(10).unwrap()),
+        ]);
+        trick
+            .play(1, AnyCard::Trump(TrumpCard::new_trump_card(10).unwrap()), &void_hand)
+            .unwrap();
+
+        let third_hand = hand_of(vec![
+            AnyCard::Trump(TrumpCard::new_trump_card(2).unwrap()),
+            AnyCard::Trump(TrumpCard::new_trump_card(15).unwrap()),
+        ]);
+
+        assert!(!trick.is_legal(&AnyCard::Trump(TrumpCard::new_trump_card(2).unwrap()), &third_hand));
+        assert!(trick.is_legal(&AnyCard::Trump(TrumpCard::new_trump_card(15).unwrap()), &third_hand));
+    }
+
+    #[test]
+    fn test_any_trump_is_legal_when_none_can_over_trump() {
+        let mut trick = Trick::new();
+        let leader_hand = hand_of(vec![AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap())]);
+        trick
+            .play(0, AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap()), &leader_hand)
+            .unwrap();
+
+        let void_hand = hand_of(vec![AnyCard::Trump(TrumpCard::new_trump_card(10).unwrap())]);
+        trick
+            .play(1, AnyCard::Trump(TrumpCard::new_trump_card(10).unwrap()), &void_hand)
+            .unwrap();
+
+        let third_hand = hand_of(vec![
+            AnyCard::Trump(TrumpCard::new_trump_card(2).unwrap()),
+            AnyCard::Trump(TrumpCard::new_trump_card(3).unwrap()),
+        ]);
+
+        assert!(trick.is_legal(&AnyCard::Trump(TrumpCard::new_trump_card(2).unwrap()), &third_hand));
+        assert!(trick.is_legal(&AnyCard::Trump(TrumpCard::new_trump_card(3).unwrap()), &third_hand));
+    }
+
+    #[test]
+    fn test_fool_never_wins_and_is_returned() {
+        let mut trick = Trick::new();
+        let leader_hand = hand_of(vec![AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap())]);
+        trick
+            .play(0, AnyCard::Color(ColorCard::new_pip(5, Color::Hearts).unwrap()), &leader_hand)
+            .unwrap();
+
+        let fool_hand = hand_of(vec![AnyCard::Trump(TrumpCard::Fool)]);
+        trick.play(1, AnyCard::Trump(TrumpCard::Fool), &fool_hand).unwrap();
+
+        let winner_hand = hand_of(vec![AnyCard::Color(ColorCard::new_face(crate::Face::King, Color::Hearts))]);
+        trick
+            .play(2, AnyCard::Color(ColorCard::new_face(crate::Face::King, Color::Hearts)), &winner_hand)
+            .unwrap();
+
+        let result = trick.winner().unwrap();
+        assert_eq!(result.winner, 2);
+        assert_eq!(result.fool_exchange, FoolExchange::ReturnedToPlayer(1));
+    }
+}